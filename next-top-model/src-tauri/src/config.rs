@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+const ENV_VAR: &str = "NEXT_TOP_MODEL_DB";
+const CONFIG_FILE_NAME: &str = "config.json";
+const DEFAULT_DB_FILE_NAME: &str = "nfl_data.db";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ConfigFile {
+    db_path: Option<String>,
+}
+
+/// Runtime override set via `set_database_path`; takes priority over
+/// everything else until the process restarts.
+static OVERRIDE_PATH: RwLock<Option<String>> = RwLock::new(None);
+
+fn app_config_dir() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("next-top-model")
+}
+
+fn config_file_path() -> PathBuf {
+    app_config_dir().join(CONFIG_FILE_NAME)
+}
+
+fn read_config_file() -> Option<String> {
+    let contents = fs::read_to_string(config_file_path()).ok()?;
+    let config: ConfigFile = serde_json::from_str(&contents).ok()?;
+    config.db_path
+}
+
+fn write_config_file(db_path: &str) -> Result<(), String> {
+    let dir = app_config_dir();
+    fs::create_dir_all(&dir).map_err(|e| {
+        format!("Failed to create config directory {}: {}", dir.display(), e)
+    })?;
+
+    let config = ConfigFile {
+        db_path: Some(db_path.to_string()),
+    };
+    let contents = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+    fs::write(config_file_path(), contents).map_err(|e| e.to_string())
+}
+
+fn default_db_path() -> String {
+    app_config_dir()
+        .join(DEFAULT_DB_FILE_NAME)
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Resolves the database path in priority order: an explicit runtime
+/// override (`set_database_path`), the `NEXT_TOP_MODEL_DB` environment
+/// variable, the `db_path` key in the app config file, and finally a
+/// default `nfl_data.db` inside the app's config directory.
+pub fn resolve_db_path() -> String {
+    if let Some(path) = OVERRIDE_PATH.read().unwrap().clone() {
+        return path;
+    }
+
+    if let Ok(path) = std::env::var(ENV_VAR) {
+        if !path.is_empty() {
+            return path;
+        }
+    }
+
+    if let Some(path) = read_config_file() {
+        return path;
+    }
+
+    default_db_path()
+}
+
+#[tauri::command]
+pub fn set_database_path(path: String) -> Result<(), String> {
+    println!("set_database_path called with path: {}", path);
+    write_config_file(&path)?;
+    *OVERRIDE_PATH.write().unwrap() = Some(path);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_database_path() -> Result<String, String> {
+    Ok(resolve_db_path())
+}
@@ -0,0 +1,88 @@
+use rusqlite::{Connection, OpenFlags};
+use std::cell::{Ref, RefCell};
+use std::path::Path;
+use std::sync::OnceLock;
+use thread_local::ThreadLocal;
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+use crate::config;
+
+const MAX_CONCURRENT_CONNECTIONS: usize = 32;
+
+/// Shared connection pool: one SQLite connection per worker thread (reused
+/// across calls instead of reopened), gated by a semaphore so we never have
+/// more than `MAX_CONCURRENT_CONNECTIONS` threads touching SQLite at once.
+pub struct Database {
+    connections: ThreadLocal<RefCell<Connection>>,
+    semaphore: Semaphore,
+}
+
+pub struct DbGuard<'a> {
+    _permit: SemaphorePermit<'a>,
+    connection: &'a RefCell<Connection>,
+}
+
+impl<'a> DbGuard<'a> {
+    pub fn connection(&self) -> Ref<'_, Connection> {
+        self.connection.borrow()
+    }
+}
+
+impl Database {
+    fn new() -> Self {
+        Database {
+            connections: ThreadLocal::new(),
+            semaphore: Semaphore::new(MAX_CONCURRENT_CONNECTIONS),
+        }
+    }
+
+    pub async fn acquire(&self) -> Result<DbGuard<'_>, String> {
+        let permit = self
+            .semaphore
+            .acquire()
+            .await
+            .map_err(|e| format!("Failed to acquire database permit: {}", e))?;
+
+        let connection = self
+            .connections
+            .get_or_try(open_thread_connection)
+            .map_err(|e| e.to_string())?;
+
+        Ok(DbGuard {
+            _permit: permit,
+            connection,
+        })
+    }
+}
+
+fn open_thread_connection() -> Result<RefCell<Connection>, String> {
+    let db_path = config::resolve_db_path();
+
+    if !Path::new(&db_path).exists() {
+        eprintln!("Database file not found at: {}", db_path);
+        return Err("Database file not found".to_string());
+    }
+
+    let uri = format!("file:{}?cache=shared", db_path);
+    let conn = Connection::open_with_flags(
+        uri,
+        OpenFlags::SQLITE_OPEN_READ_WRITE
+            | OpenFlags::SQLITE_OPEN_CREATE
+            | OpenFlags::SQLITE_OPEN_URI,
+    )
+    .map_err(|e| e.to_string())?;
+
+    Ok(RefCell::new(conn))
+}
+
+fn database() -> &'static Database {
+    static INSTANCE: OnceLock<Database> = OnceLock::new();
+    INSTANCE.get_or_init(Database::new)
+}
+
+/// Acquires a pooled, per-thread connection, bounded by the global semaphore.
+/// Replaces the old `get_db_connection`, which opened (and probed) a brand
+/// new connection on every single call.
+pub async fn get_connection() -> Result<DbGuard<'static>, String> {
+    database().acquire().await
+}
@@ -0,0 +1,148 @@
+use rusqlite::Row;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::db;
+use crate::from_row::{query_rows, FromRow};
+
+const INITIAL_RATING: f64 = 1500.0;
+const HOME_FIELD_ADVANTAGE: f64 = 65.0;
+const K_FACTOR: f64 = 20.0;
+const SEASON_REGRESSION_FACTOR: f64 = 0.75;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamEloRating {
+    team_uid: String,
+    season: i32,
+    week: Option<f64>,
+    game_uid: String,
+    rating: f64,
+}
+
+struct EloGameRow {
+    game_uid: String,
+    season: i32,
+    week: Option<f64>,
+    home_team_uid: String,
+    away_team_uid: String,
+    home_score: i32,
+    away_score: i32,
+}
+
+impl FromRow for EloGameRow {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(EloGameRow {
+            game_uid: row.get(0)?,
+            season: row.get(1)?,
+            week: row.get(2)?,
+            home_team_uid: row.get(3)?,
+            away_team_uid: row.get(4)?,
+            home_score: row.get(5)?,
+            away_score: row.get(6)?,
+        })
+    }
+}
+
+#[tauri::command]
+pub async fn get_team_elo_ratings(
+    season: Option<i32>,
+    team_uid: Option<String>,
+) -> Result<Vec<TeamEloRating>, String> {
+    println!(
+        "get_team_elo_ratings called with season: {:?}, team_uid: {:?}",
+        season, team_uid
+    );
+
+    let guard = db::get_connection().await.map_err(|e| {
+        println!("Elo: Database connection error: {}", e);
+        e
+    })?;
+    let conn = guard.connection();
+
+    let games = query_rows::<EloGameRow>(
+        &conn,
+        "
+        SELECT game_uid, season, week, home_team_uid, away_team_uid, home_score, away_score
+        FROM games
+        WHERE home_team_uid IS NOT NULL AND away_team_uid IS NOT NULL
+              AND home_score IS NOT NULL AND away_score IS NOT NULL
+        ORDER BY season ASC, game_datetime ASC
+    ",
+        &[],
+    )
+    .map_err(|e| {
+        println!("Elo: Query error: {}", e);
+        e
+    })?;
+
+    let mut ratings: HashMap<String, f64> = HashMap::new();
+    let mut history: Vec<TeamEloRating> = Vec::new();
+    let mut current_season: Option<i32> = None;
+
+    for game in &games {
+        if let Some(prev_season) = current_season {
+            if prev_season != game.season {
+                for rating in ratings.values_mut() {
+                    *rating = SEASON_REGRESSION_FACTOR * *rating
+                        + (1.0 - SEASON_REGRESSION_FACTOR) * INITIAL_RATING;
+                }
+            }
+        }
+        current_season = Some(game.season);
+
+        let r_home = *ratings
+            .entry(game.home_team_uid.clone())
+            .or_insert(INITIAL_RATING);
+        let r_away = *ratings
+            .entry(game.away_team_uid.clone())
+            .or_insert(INITIAL_RATING);
+
+        let expected_home =
+            1.0 / (1.0 + 10f64.powf((r_away - r_home - HOME_FIELD_ADVANTAGE) / 400.0));
+        let actual_home = if game.home_score > game.away_score {
+            1.0
+        } else if game.home_score == game.away_score {
+            0.5
+        } else {
+            0.0
+        };
+
+        // Margin-of-victory scaling only amplifies decided games; `ln(1) == 0`
+        // would otherwise zero out the rating update for ties.
+        let margin = (game.home_score - game.away_score).unsigned_abs();
+        let mov_multiplier = ((margin + 1) as f64).ln().max(1.0);
+        let delta = K_FACTOR * mov_multiplier * (actual_home - expected_home);
+
+        let new_r_home = r_home + delta;
+        let new_r_away = r_away - delta;
+        ratings.insert(game.home_team_uid.clone(), new_r_home);
+        ratings.insert(game.away_team_uid.clone(), new_r_away);
+
+        if season.is_some_and(|s| s != game.season) {
+            continue;
+        }
+
+        if team_uid.is_none() || team_uid.as_deref() == Some(game.home_team_uid.as_str()) {
+            history.push(TeamEloRating {
+                team_uid: game.home_team_uid.clone(),
+                season: game.season,
+                week: game.week,
+                game_uid: game.game_uid.clone(),
+                rating: new_r_home,
+            });
+        }
+
+        if team_uid.is_none() || team_uid.as_deref() == Some(game.away_team_uid.as_str()) {
+            history.push(TeamEloRating {
+                team_uid: game.away_team_uid.clone(),
+                season: game.season,
+                week: game.week,
+                game_uid: game.game_uid.clone(),
+                rating: new_r_away,
+            });
+        }
+    }
+
+    println!("Computed {} elo rating entries", history.len());
+    Ok(history)
+}
@@ -0,0 +1,98 @@
+use rusqlite::{Connection, Row};
+
+use crate::{Game, Team, TeamGameStat, TeamSeasonStat};
+
+/// Maps a single `rusqlite::Row` into an owned value. Implement this once per
+/// entity instead of hand-rolling a `query_map` closure in every command.
+pub trait FromRow: Sized {
+    fn from_row(row: &Row) -> rusqlite::Result<Self>;
+}
+
+impl FromRow for Team {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Team {
+            team_uid: row.get(0)?,
+            city: row.get(1)?,
+            name: row.get(2)?,
+            abbreviation: row.get(3)?,
+            stadium_name: row.get(4)?,
+            stadium_capacity: row.get(5)?,
+            conference: row.get(6)?,
+            division: row.get(7)?,
+        })
+    }
+}
+
+impl FromRow for Game {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Game {
+            game_uid: row.get(0)?,
+            season: row.get(1)?,
+            week: row.get(2)?,
+            game_type: row.get(3)?,
+            home_team_uid: row.get(4)?,
+            away_team_uid: row.get(5)?,
+            game_datetime: row.get(6)?,
+            venue: row.get(7)?,
+            home_score: row.get(8)?,
+            away_score: row.get(9)?,
+            overtime: row.get(10)?,
+            weather_temp: row.get(11)?,
+            weather_condition: row.get(12)?,
+            attendance: row.get(13)?,
+        })
+    }
+}
+
+impl FromRow for TeamGameStat {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(TeamGameStat {
+            stat_uid: row.get(0)?,
+            game_uid: row.get(1)?,
+            team_uid: row.get(2)?,
+            is_home_team: row.get(3)?,
+            total_yards: row.get(4)?,
+            passing_yards: row.get(5)?,
+            rushing_yards: row.get(6)?,
+            first_downs: row.get(7)?,
+            turnovers: row.get(8)?,
+            penalties: row.get(9)?,
+        })
+    }
+}
+
+impl FromRow for TeamSeasonStat {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(TeamSeasonStat {
+            stat_uid: row.get(0)?,
+            team_uid: row.get(1)?,
+            season: row.get(2)?,
+            wins: row.get(3)?,
+            losses: row.get(4)?,
+            ties: row.get(5)?,
+            win_percentage: row.get(6)?,
+        })
+    }
+}
+
+/// Prepares `sql`, binds `params`, maps every row through `T::from_row`, and
+/// collects the results, folding any step's error into the command's
+/// `Result<_, String>`.
+pub fn query_rows<T: FromRow>(
+    conn: &Connection,
+    sql: &str,
+    params: &[&dyn rusqlite::types::ToSql],
+) -> Result<Vec<T>, String> {
+    let mut stmt = conn.prepare(sql).map_err(|e| e.to_string())?;
+
+    let rows = stmt
+        .query_map(params, |row| T::from_row(row))
+        .map_err(|e| e.to_string())?;
+
+    let mut results = Vec::new();
+    for row in rows {
+        results.push(row.map_err(|e| e.to_string())?);
+    }
+
+    Ok(results)
+}
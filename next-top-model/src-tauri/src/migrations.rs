@@ -0,0 +1,173 @@
+use rusqlite::{Connection, Transaction};
+use std::fs;
+use std::path::Path;
+
+use crate::config;
+
+struct Migration {
+    version: i32,
+    description: &'static str,
+    sql: &'static str,
+}
+
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "create base schema",
+        sql: "
+            CREATE TABLE IF NOT EXISTS teams (
+                team_uid TEXT PRIMARY KEY,
+                city TEXT,
+                name TEXT NOT NULL,
+                abbreviation TEXT,
+                stadium_name TEXT,
+                stadium_capacity INTEGER,
+                conference TEXT,
+                division TEXT
+            );
+
+            CREATE TABLE IF NOT EXISTS games (
+                game_uid TEXT PRIMARY KEY,
+                season INTEGER NOT NULL,
+                week REAL,
+                game_type TEXT,
+                home_team_uid TEXT REFERENCES teams(team_uid),
+                away_team_uid TEXT REFERENCES teams(team_uid),
+                game_datetime TEXT,
+                venue TEXT,
+                home_score INTEGER,
+                away_score INTEGER,
+                overtime INTEGER
+            );
+
+            CREATE TABLE IF NOT EXISTS team_game_stats (
+                stat_uid TEXT PRIMARY KEY,
+                game_uid TEXT NOT NULL REFERENCES games(game_uid),
+                team_uid TEXT NOT NULL REFERENCES teams(team_uid),
+                is_home_team INTEGER,
+                total_yards INTEGER,
+                passing_yards INTEGER,
+                rushing_yards INTEGER,
+                first_downs INTEGER,
+                turnovers INTEGER,
+                penalties INTEGER
+            );
+
+            CREATE TABLE IF NOT EXISTS team_season_stats (
+                stat_uid TEXT PRIMARY KEY,
+                team_uid TEXT NOT NULL REFERENCES teams(team_uid),
+                season INTEGER NOT NULL,
+                wins INTEGER,
+                losses INTEGER,
+                ties INTEGER,
+                win_percentage REAL
+            );
+        ",
+    },
+    Migration {
+        version: 2,
+        description: "add weather and attendance columns to games",
+        sql: "
+            ALTER TABLE games ADD COLUMN weather_temp REAL;
+            ALTER TABLE games ADD COLUMN weather_condition TEXT;
+            ALTER TABLE games ADD COLUMN attendance INTEGER;
+        ",
+    },
+    Migration {
+        version: 3,
+        description: "add period types and scoring events",
+        sql: "
+            CREATE TABLE IF NOT EXISTS period_types (
+                period_type TEXT PRIMARY KEY,
+                short_name TEXT NOT NULL,
+                default_length_seconds INTEGER NOT NULL,
+                sort_order INTEGER NOT NULL
+            );
+
+            INSERT OR IGNORE INTO period_types (period_type, short_name, default_length_seconds, sort_order) VALUES
+                ('first', '1st', 900, 1),
+                ('second', '2nd', 900, 2),
+                ('third', '3rd', 900, 3),
+                ('OT', 'OT', 600, 4),
+                ('SO', 'SO', 0, 5);
+
+            CREATE TABLE IF NOT EXISTS scoring_events (
+                event_uid TEXT PRIMARY KEY,
+                game_uid TEXT NOT NULL REFERENCES games(game_uid),
+                team_uid TEXT REFERENCES teams(team_uid),
+                period_type TEXT NOT NULL REFERENCES period_types(period_type),
+                clock_seconds INTEGER,
+                points INTEGER NOT NULL,
+                description TEXT
+            );
+        ",
+    },
+];
+
+/// Applies any pending migrations to the resolved database, tracking the
+/// applied version with `PRAGMA user_version`. Safe to call on an empty
+/// (even nonexistent) database file or one created by an older version of
+/// the app. Called once from `run()` on startup.
+pub fn run_migrations() -> Result<(), String> {
+    let db_path = config::resolve_db_path();
+
+    if let Some(parent) = Path::new(&db_path).parent() {
+        fs::create_dir_all(parent).map_err(|e| {
+            format!("Failed to create database directory {}: {}", parent.display(), e)
+        })?;
+    }
+
+    let mut conn = Connection::open(&db_path).map_err(|e| e.to_string())?;
+
+    let current_version: i32 = conn
+        .query_row("PRAGMA user_version", [], |row| row.get(0))
+        .map_err(|e| e.to_string())?;
+
+    for migration in MIGRATIONS.iter().filter(|m| m.version > current_version) {
+        println!(
+            "Applying migration {}: {}",
+            migration.version, migration.description
+        );
+
+        let tx = conn.transaction().map_err(|e| e.to_string())?;
+        apply_migration_sql(&tx, migration.sql)
+            .map_err(|e| format!("Migration {} failed: {}", migration.version, e))?;
+        tx.pragma_update(None, "user_version", migration.version)
+            .map_err(|e| e.to_string())?;
+        tx.commit().map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+/// Runs each statement in a migration individually rather than as one
+/// `execute_batch`, so a statement that only fails because it was already
+/// applied to a pre-existing database (most commonly `ALTER TABLE ... ADD
+/// COLUMN` on a schema an older build of the app already created) can be
+/// skipped instead of aborting the whole migration.
+fn apply_migration_sql(tx: &Transaction, sql: &str) -> Result<(), String> {
+    for statement in sql.split(';') {
+        let statement = statement.trim();
+        if statement.is_empty() {
+            continue;
+        }
+
+        if let Err(e) = tx.execute(statement, []) {
+            if is_duplicate_column_error(statement, &e) {
+                println!(
+                    "Skipping already-applied statement: {}",
+                    statement.split_whitespace().collect::<Vec<_>>().join(" ")
+                );
+                continue;
+            }
+            return Err(e.to_string());
+        }
+    }
+
+    Ok(())
+}
+
+fn is_duplicate_column_error(statement: &str, error: &rusqlite::Error) -> bool {
+    let is_add_column = statement.to_uppercase().contains("ADD COLUMN");
+    is_add_column && error.to_string().contains("duplicate column name")
+}
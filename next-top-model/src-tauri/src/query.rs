@@ -0,0 +1,83 @@
+use rusqlite::types::ValueRef;
+use rusqlite::{Connection, OpenFlags};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::config;
+
+#[derive(Debug, Serialize)]
+pub struct QueryResult {
+    rows: Vec<HashMap<String, Value>>,
+    row_count: usize,
+}
+
+/// Runs an ad-hoc, read-only SQL statement against the database and returns
+/// the result set as column name -> value maps. Statements other than
+/// `SELECT`/`WITH` are rejected, and the connection itself is opened
+/// `SQLITE_OPEN_READ_ONLY` as a second line of defense.
+#[tauri::command]
+pub async fn run_query(sql: String) -> Result<QueryResult, String> {
+    println!("run_query called with sql: {}", sql);
+
+    let normalized = sql.trim_start().to_lowercase();
+    if !(normalized.starts_with("select") || normalized.starts_with("with")) {
+        return Err("Only SELECT or WITH statements are allowed".to_string());
+    }
+
+    let db_path = config::resolve_db_path();
+    let conn = Connection::open_with_flags(
+        &db_path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_URI,
+    )
+    .map_err(|e| {
+        println!("run_query: Database connection error: {}", e);
+        e.to_string()
+    })?;
+
+    let mut stmt = conn.prepare(&sql).map_err(|e| {
+        println!("run_query: Query preparation error: {}", e);
+        e.to_string()
+    })?;
+
+    let column_names: Vec<String> = stmt
+        .column_names()
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+
+    let row_iter = stmt
+        .query_map([], |row| {
+            let mut record = HashMap::with_capacity(column_names.len());
+            for (index, name) in column_names.iter().enumerate() {
+                let value = match row.get_ref(index)? {
+                    ValueRef::Null => Value::Null,
+                    ValueRef::Integer(n) => Value::from(n),
+                    ValueRef::Real(f) => Value::from(f),
+                    ValueRef::Text(t) => Value::from(String::from_utf8_lossy(t).into_owned()),
+                    ValueRef::Blob(b) => Value::from(b.to_vec()),
+                };
+                record.insert(name.clone(), value);
+            }
+            Ok(record)
+        })
+        .map_err(|e| {
+            println!("run_query: Query execution error: {}", e);
+            e.to_string()
+        })?;
+
+    let mut rows = Vec::new();
+    for row in row_iter {
+        rows.push(row.map_err(|e| {
+            println!("run_query: Row processing error: {}", e);
+            e.to_string()
+        })?);
+    }
+
+    println!("run_query returned {} rows", rows.len());
+
+    Ok(QueryResult {
+        row_count: rows.len(),
+        rows,
+    })
+}
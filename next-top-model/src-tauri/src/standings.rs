@@ -0,0 +1,264 @@
+use rusqlite::Row;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::db;
+use crate::from_row::{query_rows, FromRow};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScoringEvent {
+    event_uid: String,
+    game_uid: String,
+    team_uid: Option<String>,
+    period_type: String,
+    clock_seconds: Option<i32>,
+    points: i32,
+    description: Option<String>,
+}
+
+impl FromRow for ScoringEvent {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(ScoringEvent {
+            event_uid: row.get(0)?,
+            game_uid: row.get(1)?,
+            team_uid: row.get(2)?,
+            period_type: row.get(3)?,
+            clock_seconds: row.get(4)?,
+            points: row.get(5)?,
+            description: row.get(6)?,
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Standing {
+    team_uid: String,
+    conference: Option<String>,
+    division: Option<String>,
+    wins: i32,
+    losses: i32,
+    ties: i32,
+    points_for: i32,
+    points_against: i32,
+    division_rank: i32,
+}
+
+impl Standing {
+    fn win_percentage(&self) -> f64 {
+        let games = self.wins + self.losses + self.ties;
+        if games == 0 {
+            0.0
+        } else {
+            (self.wins as f64 + 0.5 * self.ties as f64) / games as f64
+        }
+    }
+
+    fn point_differential(&self) -> i32 {
+        self.points_for - self.points_against
+    }
+}
+
+struct TeamRecord {
+    conference: Option<String>,
+    division: Option<String>,
+    wins: i32,
+    losses: i32,
+    ties: i32,
+    points_for: i32,
+    points_against: i32,
+}
+
+struct TeamInfo {
+    team_uid: String,
+    conference: Option<String>,
+    division: Option<String>,
+}
+
+impl FromRow for TeamInfo {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(TeamInfo {
+            team_uid: row.get(0)?,
+            conference: row.get(1)?,
+            division: row.get(2)?,
+        })
+    }
+}
+
+struct StandingsGameRow {
+    home_team_uid: String,
+    away_team_uid: String,
+    home_score: i32,
+    away_score: i32,
+}
+
+impl FromRow for StandingsGameRow {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(StandingsGameRow {
+            home_team_uid: row.get(0)?,
+            away_team_uid: row.get(1)?,
+            home_score: row.get(2)?,
+            away_score: row.get(3)?,
+        })
+    }
+}
+
+#[tauri::command]
+pub async fn get_scoring_events(game_uid: String) -> Result<Vec<ScoringEvent>, String> {
+    println!("get_scoring_events called with game_uid: {}", game_uid);
+
+    let guard = db::get_connection().await.map_err(|e| {
+        println!("Scoring events: Database connection error: {}", e);
+        e
+    })?;
+    let conn = guard.connection();
+
+    // clock_seconds is the game-clock reading (time remaining, counting down),
+    // so within a period it must sort descending to read in chronological order.
+    let events = query_rows::<ScoringEvent>(
+        &conn,
+        "
+        SELECT se.event_uid, se.game_uid, se.team_uid, se.period_type,
+               se.clock_seconds, se.points, se.description
+        FROM scoring_events se
+        JOIN period_types pt ON pt.period_type = se.period_type
+        WHERE se.game_uid = ?
+        ORDER BY pt.sort_order ASC, se.clock_seconds DESC
+    ",
+        &[&game_uid],
+    )
+    .map_err(|e| {
+        println!("Scoring events: Query error: {}", e);
+        e
+    })?;
+
+    println!("Retrieved {} scoring events", events.len());
+    Ok(events)
+}
+
+#[tauri::command]
+pub async fn get_standings(season: i32) -> Result<Vec<Standing>, String> {
+    println!("get_standings called with season: {}", season);
+
+    let guard = db::get_connection().await.map_err(|e| {
+        println!("Standings: Database connection error: {}", e);
+        e
+    })?;
+    let conn = guard.connection();
+
+    let mut records: HashMap<String, TeamRecord> =
+        query_rows::<TeamInfo>(&conn, "SELECT team_uid, conference, division FROM teams", &[])
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|team| {
+                (
+                    team.team_uid,
+                    TeamRecord {
+                        conference: team.conference,
+                        division: team.division,
+                        wins: 0,
+                        losses: 0,
+                        ties: 0,
+                        points_for: 0,
+                        points_against: 0,
+                    },
+                )
+            })
+            .collect();
+
+    // Regular-season games only; preseason/playoff games would otherwise
+    // inflate records and skew division rank.
+    let games = query_rows::<StandingsGameRow>(
+        &conn,
+        "
+        SELECT home_team_uid, away_team_uid, home_score, away_score
+        FROM games
+        WHERE season = ? AND game_type = 'REG'
+              AND home_team_uid IS NOT NULL AND away_team_uid IS NOT NULL
+              AND home_score IS NOT NULL AND away_score IS NOT NULL
+    ",
+        &[&season],
+    )
+    .map_err(|e| e.to_string())?;
+
+    for game in games {
+        let StandingsGameRow {
+            home_team_uid,
+            away_team_uid,
+            home_score,
+            away_score,
+        } = game;
+        // A game that ends level is a tie outright; the `overtime` flag only
+        // indicates extra periods were played, not whether the result was decisive.
+        let is_tie = home_score == away_score;
+
+        if let Some(home) = records.get_mut(&home_team_uid) {
+            home.points_for += home_score;
+            home.points_against += away_score;
+            if is_tie {
+                home.ties += 1;
+            } else if home_score > away_score {
+                home.wins += 1;
+            } else {
+                home.losses += 1;
+            }
+        }
+
+        if let Some(away) = records.get_mut(&away_team_uid) {
+            away.points_for += away_score;
+            away.points_against += home_score;
+            if is_tie {
+                away.ties += 1;
+            } else if away_score > home_score {
+                away.wins += 1;
+            } else {
+                away.losses += 1;
+            }
+        }
+    }
+
+    let mut standings: Vec<Standing> = records
+        .into_iter()
+        .map(|(team_uid, record)| Standing {
+            team_uid,
+            conference: record.conference,
+            division: record.division,
+            wins: record.wins,
+            losses: record.losses,
+            ties: record.ties,
+            points_for: record.points_for,
+            points_against: record.points_against,
+            division_rank: 0,
+        })
+        .collect();
+
+    standings.sort_by(|a, b| {
+        (a.conference.clone(), a.division.clone()).cmp(&(b.conference.clone(), b.division.clone()))
+    });
+
+    let mut index = 0;
+    while index < standings.len() {
+        let mut end = index;
+        while end < standings.len()
+            && standings[end].conference == standings[index].conference
+            && standings[end].division == standings[index].division
+        {
+            end += 1;
+        }
+
+        standings[index..end].sort_by(|a, b| {
+            b.win_percentage()
+                .partial_cmp(&a.win_percentage())
+                .unwrap()
+                .then(b.point_differential().cmp(&a.point_differential()))
+        });
+
+        for (rank, standing) in standings[index..end].iter_mut().enumerate() {
+            standing.division_rank = rank as i32 + 1;
+        }
+
+        index = end;
+    }
+
+    println!("Computed standings for {} teams", standings.len());
+    Ok(standings)
+}